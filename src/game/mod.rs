@@ -1,6 +1,15 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use matrix::Matrix;
 
+mod rng;
+use self::rng::Rng;
+
 const BOARD_SIZE: (usize, usize) = (4, 4);
+const WIN_TARGET: u32 = 2048;
+
+/// Odds that a spawned tile is a `4` rather than a `2`.
+const SPAWN_FOUR_CHANCE: f64 = 0.1;
 
 pub enum Direction {
     Left,
@@ -9,6 +18,14 @@ pub enum Direction {
     Down
 }
 
+/// Represents the state of play a `Game` is currently in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GameStatus {
+    Playing,
+    Won,
+    Lost
+}
+
 pub trait Game<S> {
 
     /// Produces the score for the `Game`.
@@ -17,25 +34,60 @@ pub trait Game<S> {
     /// Attempts to slide the `Game` in the specified direction, producing a
     /// new `Game` containing the new state of the `Game`.
     fn slide(self, dir: Direction) -> Self;
+
+    /// Places a new tile in a uniformly random empty cell.
+    fn spawn(&mut self);
+
+    /// Determines whether any legal move remains, i.e. there is an empty
+    /// cell or two orthogonally adjacent cells share a value.
+    fn moves_available(&self) -> bool;
+
+    /// Produces the current `GameStatus` of the `Game`.
+    fn status(&self) -> GameStatus;
 }
 
 /// Represents a sliding game.
 pub struct SlideGame {
     board: Matrix<u32>,
-    turns: usize
+    turns: usize,
+    rng: Rng,
+    target: u32
 }
 
-impl Default for SlideGame {
+impl SlideGame {
 
-    /// Produces a default `SlideGame`. A default `SlideGame` contains an 4x4
-    /// board, wherein each tile has a value of `0`.
-    fn default() -> Self {
+    /// Produces a `SlideGame` seeded with the specified value, so that its
+    /// sequence of spawned tiles is reproducible.
+    pub fn with_seed(seed: u64) -> Self {
         let (rows, cols) = BOARD_SIZE;
         Self {
             board: Matrix::new(rows, cols),
-            turns: 0
+            turns: 0,
+            rng: Rng::new(seed),
+            target: WIN_TARGET
         }
     }
+
+    /// Overrides the tile value that must appear on the board for the
+    /// `SlideGame` to be considered `Won` (defaults to `2048`).
+    pub fn with_target(mut self, target: u32) -> Self {
+        self.target = target;
+        self
+    }
+}
+
+impl Default for SlideGame {
+
+    /// Produces a default `SlideGame`. A default `SlideGame` contains an 4x4
+    /// board, wherein each tile has a value of `0`.
+    fn default() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        Self::with_seed(seed)
+    }
 }
 
 impl Game<u32> for SlideGame {
@@ -47,7 +99,9 @@ impl Game<u32> for SlideGame {
     }
 
     /// Attempts to slide the `SlideGame` in the specified `Direction`.
-    /// Consumes the `SlideGame` and produces a `SlideGame` with the new state.
+    /// Consumes the `SlideGame` and produces a `SlideGame` with the new
+    /// state. A new tile is only spawned when the move actually changed the
+    /// board.
     fn slide(self, dir: Direction) -> Self {
         use self::Direction::*;
         let (rows, cols) = BOARD_SIZE;
@@ -58,21 +112,99 @@ impl Game<u32> for SlideGame {
             Up => slide_up(&self.board, &mut buf),
             Down => slide_down(&self.board, &mut buf),
         }
-        Self {
+
+        let changed = buf.iter().zip(self.board.iter()).any(|(a, b)| a != b);
+
+        let mut next = Self {
             board: buf,
-            turns: self.turns + 1
+            turns: self.turns + 1,
+            rng: self.rng,
+            target: self.target
+        };
+
+        if changed {
+            next.spawn();
+        }
+
+        next
+    }
+
+    /// Places a `2` (or, with a 10% chance, a `4`) in a uniformly random
+    /// empty cell of the board. Does nothing if the board has no empty cell.
+    fn spawn(&mut self) {
+        let empties: Vec<(usize, usize)> = self.board.indices()
+            .filter(|&(_, _, &v)| v == 0)
+            .map(|(i, j, _)| (i, j))
+            .collect();
+
+        if empties.is_empty() {
+            return;
+        }
+
+        let (i, j) = empties[self.rng.gen_range(empties.len())];
+        let val = if self.rng.gen_bool(SPAWN_FOUR_CHANCE) { 4 } else { 2 };
+        self.board.set(i, j, val);
+    }
+
+    /// A move remains available if the board has an empty cell or any two
+    /// orthogonally adjacent cells share a value.
+    fn moves_available(&self) -> bool {
+        let (rows, cols) = self.board.size();
+        self.board.indices().any(|(i, j, &v)| {
+            v == 0
+                || (i + 1 < rows && *self.board.get(i + 1, j) == v)
+                || (j + 1 < cols && *self.board.get(i, j + 1) == v)
+        })
+    }
+
+    /// The `SlideGame` is `Won` once a tile reaches its target value,
+    /// `Lost` once no move would change the board, and `Playing` otherwise.
+    fn status(&self) -> GameStatus {
+        if self.board.iter().any(|&v| v >= self.target) {
+            GameStatus::Won
+        } else if !self.moves_available() {
+            GameStatus::Lost
+        } else {
+            GameStatus::Playing
         }
     }
 }
 
+/// Slides a single line of tiles toward its front, compressing out zeros and
+/// merging equal adjacent tiles exactly once per move.
+///
+/// Implements the standard 2048 move as three phases: compress the non-zero
+/// tiles, merge adjacent equal pairs from front to back (a tile produced by a
+/// merge is never merged again this move), then compress once more and pad
+/// the remainder with zeros so the line keeps its original length.
+fn slide_line(line: &[u32]) -> Vec<u32> {
+    let compressed: Vec<u32> = line.iter().cloned().filter(|&v| v != 0).collect();
+
+    let mut merged = Vec::with_capacity(compressed.len());
+    let mut i = 0;
+    while i < compressed.len() {
+        if i + 1 < compressed.len() && compressed[i] == compressed[i + 1] {
+            merged.push(compressed[i] + compressed[i + 1]);
+            i += 2;
+        } else {
+            merged.push(compressed[i]);
+            i += 1;
+        }
+    }
+
+    merged.resize(line.len(), 0);
+    merged
+}
+
 /// Slides the elements of the specified source `Matrix` left, saving the
 /// results in the specified destination `Matrix`.
 fn slide_left(source: &Matrix<u32>, dest: &mut Matrix<u32>) {
     // Slide row by row
     for i in 0..source.rows() {
-        let row = source.row(i);
-        for j in 0..source.cols() {
-            row[1];
+        let row: Vec<u32> = source.row(i).cloned().collect();
+        let result = slide_line(&row);
+        for (j, val) in result.into_iter().enumerate() {
+            dest.set(i, j, val);
         }
     }
 }
@@ -80,17 +212,135 @@ fn slide_left(source: &Matrix<u32>, dest: &mut Matrix<u32>) {
 /// Slides the elements of the specified source `Matrix` right, saving the
 /// results in the specified destination `Matrix`.
 fn slide_right(source: &Matrix<u32>, dest: &mut Matrix<u32>) {
-
+    for i in 0..source.rows() {
+        let mut row: Vec<u32> = source.row(i).cloned().collect();
+        row.reverse();
+        let mut result = slide_line(&row);
+        result.reverse();
+        for (j, val) in result.into_iter().enumerate() {
+            dest.set(i, j, val);
+        }
+    }
 }
 
 /// Slides the elements of the specified source `Matrix` up, saving the
 /// results in the specified destination `Matrix`.
 fn slide_up(source: &Matrix<u32>, dest: &mut Matrix<u32>) {
-
+    let transposed = source.transpose();
+    let mut buf = Matrix::<u32>::new(transposed.rows(), transposed.cols());
+    slide_left(&transposed, &mut buf);
+    let result = buf.transpose();
+    for i in 0..result.rows() {
+        for j in 0..result.cols() {
+            dest.set(i, j, *result.get(i, j));
+        }
+    }
 }
 
 /// Slides the elements of the specified source `Matrix` down, saving the
 /// results in the specified destination `Matrix`.
 fn slide_down(source: &Matrix<u32>, dest: &mut Matrix<u32>) {
+    let transposed = source.transpose();
+    let mut buf = Matrix::<u32>::new(transposed.rows(), transposed.cols());
+    slide_right(&transposed, &mut buf);
+    let result = buf.transpose();
+    for i in 0..result.rows() {
+        for j in 0..result.cols() {
+            dest.set(i, j, *result.get(i, j));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slide_line_is_idempotent_when_no_move_is_possible() {
+        let line = vec![2, 4, 8, 16];
+        assert_eq!(slide_line(&line), line);
+    }
+
+    #[test]
+    fn slide_line_merges_left_to_right_without_double_merging() {
+        assert_eq!(slide_line(&[2, 2, 2, 2]), vec![4, 4, 0, 0]);
+    }
+
+    #[test]
+    fn slide_line_does_not_merge_a_tile_twice_in_one_move() {
+        assert_eq!(slide_line(&[4, 4, 8, 0]), vec![8, 8, 0, 0]);
+    }
 
+    /// Builds a `SlideGame` with an explicit board, for tests that need
+    /// specific tile layouts rather than a freshly spawned one.
+    fn game_with_board(seed: u64, cells: &[(usize, usize, u32)]) -> SlideGame {
+        let (rows, cols) = BOARD_SIZE;
+        let mut board = Matrix::new(rows, cols);
+        for &(i, j, val) in cells {
+            board.set(i, j, val);
+        }
+
+        SlideGame {
+            board,
+            turns: 0,
+            rng: Rng::new(seed),
+            target: WIN_TARGET
+        }
+    }
+
+    #[test]
+    fn same_seed_spawns_the_same_tile() {
+        let mut a = SlideGame::with_seed(42);
+        let mut b = SlideGame::with_seed(42);
+        a.spawn();
+        b.spawn();
+
+        for i in 0..BOARD_SIZE.0 {
+            for j in 0..BOARD_SIZE.1 {
+                assert_eq!(a.board.get(i, j), b.board.get(i, j));
+            }
+        }
+    }
+
+    #[test]
+    fn moves_available_is_false_on_a_full_board_with_no_merges() {
+        let game = game_with_board(1, &[
+            (0, 0, 2), (0, 1, 4), (0, 2, 2), (0, 3, 4),
+            (1, 0, 4), (1, 1, 2), (1, 2, 4), (1, 3, 2),
+            (2, 0, 2), (2, 1, 4), (2, 2, 2), (2, 3, 4),
+            (3, 0, 4), (3, 1, 2), (3, 2, 4), (3, 3, 2),
+        ]);
+
+        assert!(!game.moves_available());
+    }
+
+    #[test]
+    fn moves_available_is_true_when_an_adjacent_pair_can_merge() {
+        let game = game_with_board(1, &[
+            (0, 0, 2), (0, 1, 2), (0, 2, 4), (0, 3, 8),
+            (1, 0, 4), (1, 1, 8), (1, 2, 2), (1, 3, 4),
+            (2, 0, 2), (2, 1, 4), (2, 2, 8), (2, 3, 2),
+            (3, 0, 4), (3, 1, 2), (3, 2, 4), (3, 3, 8),
+        ]);
+
+        assert!(game.moves_available());
+    }
+
+    #[test]
+    fn status_reports_won_once_the_target_tile_appears() {
+        let game = game_with_board(1, &[(0, 0, 8)]).with_target(8);
+        assert_eq!(game.status(), GameStatus::Won);
+    }
+
+    #[test]
+    fn status_reports_lost_once_no_move_remains() {
+        let game = game_with_board(1, &[
+            (0, 0, 2), (0, 1, 4), (0, 2, 2), (0, 3, 4),
+            (1, 0, 4), (1, 1, 2), (1, 2, 4), (1, 3, 2),
+            (2, 0, 2), (2, 1, 4), (2, 2, 2), (2, 3, 4),
+            (3, 0, 4), (3, 1, 2), (3, 2, 4), (3, 3, 2),
+        ]);
+
+        assert_eq!(game.status(), GameStatus::Lost);
+    }
 }
\ No newline at end of file
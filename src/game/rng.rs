@@ -0,0 +1,41 @@
+/// A minimal xorshift64 pseudo-random number generator.
+///
+/// The game only needs a seedable source of randomness for tile spawning, so
+/// this stays dependency-free rather than pulling in an external RNG crate.
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+
+    /// Creates a new `Rng` seeded with the specified value. A seed of `0` is
+    /// substituted with a fixed non-zero value, since xorshift can never
+    /// leave the all-zero state.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 { 0x9E3779B97F4A7C15 } else { seed }
+        }
+    }
+
+    /// Produces the next pseudo-random `u64` in the sequence.
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// Produces a uniformly-distributed `usize` in the range `[0, bound)`.
+    pub fn gen_range(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Produces `true` with the specified probability, expressed as a value
+    /// in `[0.0, 1.0]`.
+    pub fn gen_bool(&mut self, probability: f64) -> bool {
+        let threshold = (probability * u64::max_value() as f64) as u64;
+        self.next_u64() < threshold
+    }
+}
@@ -45,4 +45,104 @@ macro_rules! matrix {
     [$($($el:expr),*);*] => (::matrix::Matrix::from(
         vec2d![$($($el),*);*]
     ))
-}
\ No newline at end of file
+}
+
+use super::Matrix;
+
+/// Generates arithmetic trait impls for `Matrix<T>`, following the
+/// `impl_matrix_op`-style macro used by the vector-victor crate so each trait
+/// doesn't have to be hand-written.
+///
+/// Five shapes of invocation are supported:
+/// - `binary` / `binary_assign` — element-wise ops between two `Matrix`
+///   values of equal shape, panicking otherwise.
+/// - `unary` — the single-operand `Neg` op.
+/// - `scalar` / `scalar_assign` — ops that apply one `T` value to every
+///   element.
+macro_rules! impl_matrix_op {
+    (binary $trait:ident, $method:ident, $op:tt) => {
+        impl<T> ::std::ops::$trait for Matrix<T>
+            where T: ::std::ops::$trait<Output = T>
+        {
+            type Output = Matrix<T>;
+
+            fn $method(self, rhs: Matrix<T>) -> Matrix<T> {
+                if self.size() != rhs.size() {
+                    panic!("Matrix::{} requires operands of equal shape", stringify!($method));
+                }
+
+                let data = self.data.into_iter()
+                    .zip(rhs.data.into_iter())
+                    .map(|(a, b)| a $op b)
+                    .collect();
+
+                Matrix { data, rows: self.rows, cols: self.cols }
+            }
+        }
+    };
+
+    (binary_assign $trait:ident, $method:ident, $op:tt) => {
+        impl<T> ::std::ops::$trait for Matrix<T>
+            where T: ::std::ops::$trait
+        {
+            fn $method(&mut self, rhs: Matrix<T>) {
+                if self.size() != rhs.size() {
+                    panic!("Matrix::{} requires operands of equal shape", stringify!($method));
+                }
+
+                for (a, b) in self.data.iter_mut().zip(rhs.data.into_iter()) {
+                    *a $op b;
+                }
+            }
+        }
+    };
+
+    (unary $trait:ident, $method:ident, $op:tt) => {
+        impl<T> ::std::ops::$trait for Matrix<T>
+            where T: ::std::ops::$trait<Output = T>
+        {
+            type Output = Matrix<T>;
+
+            fn $method(self) -> Matrix<T> {
+                let data = self.data.into_iter().map(|a| $op a).collect();
+                Matrix { data, rows: self.rows, cols: self.cols }
+            }
+        }
+    };
+
+    (scalar $trait:ident, $method:ident, $op:tt) => {
+        impl<T> ::std::ops::$trait<T> for Matrix<T>
+            where T: Clone + ::std::ops::$trait<Output = T>
+        {
+            type Output = Matrix<T>;
+
+            fn $method(self, scalar: T) -> Matrix<T> {
+                let data = self.data.into_iter()
+                    .map(|a| a $op scalar.clone())
+                    .collect();
+
+                Matrix { data, rows: self.rows, cols: self.cols }
+            }
+        }
+    };
+
+    (scalar_assign $trait:ident, $method:ident, $op:tt) => {
+        impl<T> ::std::ops::$trait<T> for Matrix<T>
+            where T: Clone + ::std::ops::$trait<T>
+        {
+            fn $method(&mut self, scalar: T) {
+                for a in self.data.iter_mut() {
+                    *a $op scalar.clone();
+                }
+            }
+        }
+    };
+}
+
+impl_matrix_op!(binary Add, add, +);
+impl_matrix_op!(binary Sub, sub, -);
+impl_matrix_op!(unary Neg, neg, -);
+impl_matrix_op!(binary_assign AddAssign, add_assign, +=);
+impl_matrix_op!(binary_assign SubAssign, sub_assign, -=);
+impl_matrix_op!(scalar Mul, mul, *);
+impl_matrix_op!(scalar_assign MulAssign, mul_assign, *=);
\ No newline at end of file
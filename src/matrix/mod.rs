@@ -1,5 +1,5 @@
 use std::fmt::{Display, Formatter, Error};
-use std::ops::{Index};
+use std::ops::{Index, IndexMut};
 
 #[macro_use]
 pub mod macros;
@@ -50,6 +50,17 @@ impl<T> Matrix<T> {
         self.data[index] = val;
     }
 
+    /// Produces a mutable reference to the element at the specified row and
+    /// column indices in the `Matrix`, or `None` if the indices are out of
+    /// bounds.
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if i >= self.rows() || j >= self.cols() {
+            return None;
+        }
+        let index = self.index(i, j);
+        self.data.get_mut(index)
+    }
+
     /// Produces a `Iterator` containing references to each value in the
     /// specified row of the `Matrix`.
     pub fn row(&self, i: usize) -> Row<T> {
@@ -103,6 +114,33 @@ impl<T> Matrix<T> {
             index: 0
         }
     }
+
+    /// Produces an `Iterator` containing mutable references to each value in
+    /// the `Matrix`, in the same order as `iter`.
+    pub fn iter_mut(&mut self) -> MatrixIterMut<T> {
+        MatrixIterMut {
+            iter: self.data.iter_mut()
+        }
+    }
+
+    /// Produces an `Iterator` containing `(row, col, &value)` triples for
+    /// every cell in the `Matrix`, in the same order as `iter`.
+    pub fn indices(&self) -> Indices<T> {
+        Indices {
+            matrix: self,
+            index: 0
+        }
+    }
+
+    /// Produces an `Iterator` containing `(row, col, &mut value)` triples for
+    /// every cell in the `Matrix`, in the same order as `iter`.
+    pub fn indices_mut(&mut self) -> IndicesMut<T> {
+        let cols = self.cols();
+        IndicesMut {
+            iter: self.data.iter_mut().enumerate(),
+            cols
+        }
+    }
 }
 
 impl<T> Matrix<T> where T: Clone {
@@ -132,6 +170,148 @@ impl<T> Matrix<T> where T: Clone + Default {
     }
 }
 
+impl<T> Matrix<T> where T: Clone + Default + Into<f64> + From<f64> {
+
+    /// Produces the `n` by `n` identity `Matrix`.
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::new(n, n);
+        for i in 0..n {
+            m.set(i, i, T::from(1.0));
+        }
+        m
+    }
+
+    /// Produces the submatrix formed by removing row `i` and column `j` from
+    /// the `Matrix`.
+    ///
+    /// ### Precondition
+    /// `self` must be a square `Matrix` with at least 2 rows and columns.
+    pub fn minor(&self, i: usize, j: usize) -> Self {
+        let (rows, cols) = self.size();
+        if rows != cols {
+            panic!("Matrix::minor cannot be called on a non-square Matrix");
+        }
+        if rows < 2 {
+            panic!("Matrix::minor requires a Matrix with at least 2 rows and columns");
+        }
+
+        let mut data = Vec::with_capacity((rows - 1) * (cols - 1));
+        for r in 0..rows {
+            if r == i {
+                continue;
+            }
+            for c in 0..cols {
+                if c == j {
+                    continue;
+                }
+                data.push(self.get(r, c).clone());
+            }
+        }
+
+        Self { data, rows: rows - 1, cols: cols - 1 }
+    }
+
+    /// Decomposes the `Matrix` into `LU` form using partial pivoting,
+    /// producing the working buffer of `f64` values (`L`'s multipliers
+    /// stored below the diagonal, `U` above and on it), the row permutation
+    /// applied during pivoting, and the sign of that permutation. Produces
+    /// `None` if a pivot is found to be singular.
+    fn lu_decompose(&self) -> Option<(Vec<Vec<f64>>, Vec<usize>, f64)> {
+        let (rows, cols) = self.size();
+        if rows != cols {
+            panic!("Matrix::lu_decompose cannot be called on a non-square Matrix");
+        }
+        let n = rows;
+
+        let mut buf: Vec<Vec<f64>> = (0..n)
+            .map(|i| (0..n).map(|j| self.get(i, j).clone().into()).collect())
+            .collect();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut sign = 1.0;
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&a, &b| buf[a][k].abs().partial_cmp(&buf[b][k].abs()).unwrap())
+                .unwrap();
+
+            if buf[pivot][k].abs() < 1e-10 {
+                return None;
+            }
+
+            if pivot != k {
+                buf.swap(k, pivot);
+                perm.swap(k, pivot);
+                sign = -sign;
+            }
+
+            for r in (k + 1)..n {
+                let f = buf[r][k] / buf[k][k];
+                buf[r][k] = f;
+                for c in (k + 1)..n {
+                    buf[r][c] -= f * buf[k][c];
+                }
+            }
+        }
+
+        Some((buf, perm, sign))
+    }
+
+    /// Produces the determinant of the `Matrix`, computed via `LU`
+    /// decomposition with partial pivoting rather than recursive cofactor
+    /// expansion.
+    ///
+    /// ### Precondition
+    /// `self` must be a square `Matrix`.
+    pub fn determinant(&self) -> f64 {
+        let n = self.rows();
+        match self.lu_decompose() {
+            Some((buf, _, sign)) => (0..n).fold(sign, |acc, i| acc * buf[i][i]),
+            None => 0.0
+        }
+    }
+
+    /// Produces the inverse of the `Matrix`, or `None` if the `Matrix` is
+    /// singular.
+    ///
+    /// ### Precondition
+    /// `self` must be a square `Matrix`.
+    pub fn inverse(&self) -> Option<Self> {
+        let n = self.rows();
+        let (buf, perm, _) = self.lu_decompose()?;
+
+        let mut inv = vec![vec![0.0; n]; n];
+        for col in 0..n {
+            // Apply the row permutation to the unit column e_col.
+            let mut b: Vec<f64> = (0..n)
+                .map(|r| if perm[r] == col { 1.0 } else { 0.0 })
+                .collect();
+
+            // Forward-substitute through the unit-diagonal L.
+            for r in 0..n {
+                for c in 0..r {
+                    let f = buf[r][c];
+                    b[r] -= f * b[c];
+                }
+            }
+
+            // Back-substitute through U.
+            for r in (0..n).rev() {
+                for c in (r + 1)..n {
+                    b[r] -= buf[r][c] * b[c];
+                }
+                b[r] /= buf[r][r];
+            }
+
+            for r in 0..n {
+                inv[r][col] = b[r];
+            }
+        }
+
+        let data: Vec<T> = inv.into_iter().flatten().map(T::from).collect();
+        Some(Self { data, rows: n, cols: n })
+    }
+}
+
 impl<T> Display for Matrix<T> where T: Clone + Display {
 
     /// Formats the `Matrix` to the specified `Formatter`.
@@ -181,6 +361,25 @@ impl<T> Display for Matrix<T> where T: Clone + Display {
     }
 }
 
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    /// Produces a reference to the element at the specified `(row, col)`
+    /// indices in the `Matrix`.
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        self.get(i, j)
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+
+    /// Produces a mutable reference to the element at the specified
+    /// `(row, col)` indices in the `Matrix`.
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        self.get_mut(i, j).unwrap()
+    }
+}
+
 impl<T> From<Vec<Vec<T>>> for Matrix<T> where T: Clone + Default {
 
     /// Converts the specified two-dimensional `Vec` into an N by M `Matrix`,
@@ -363,4 +562,109 @@ impl<'a, T: 'a> Iterator for MatrixIterator<'a, T> {
             None => None
         }
     }
+}
+
+pub struct MatrixIterMut<'a, T: 'a> {
+    iter: ::std::slice::IterMut<'a, T>
+}
+
+impl<'a, T: 'a> Iterator for MatrixIterMut<'a, T> {
+    type Item = &'a mut T;
+
+    /// Produces a mutable reference to the next element in the `Matrix`, in
+    /// the same order as `MatrixIterator`.
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.iter.next()
+    }
+}
+
+/// Represents a coordinate-aware iteration over the values of a `Matrix`.
+pub struct Indices<'a, T: 'a> {
+    matrix: &'a Matrix<T>,
+    index: usize
+}
+
+impl<'a, T: 'a> Iterator for Indices<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    /// Produces the row index, column index, and a reference to the value of
+    /// the next cell in the `Matrix`, or `None` if the `Iterator` has reached
+    /// its end.
+    fn next(&mut self) -> Option<(usize, usize, &'a T)> {
+        let cols = self.matrix.cols();
+        self.matrix.data.get(self.index).map(|val| {
+            let (i, j) = (self.index / cols, self.index % cols);
+            self.index += 1;
+            (i, j, val)
+        })
+    }
+}
+
+/// Represents a coordinate-aware mutable iteration over the values of a
+/// `Matrix`.
+pub struct IndicesMut<'a, T: 'a> {
+    iter: ::std::iter::Enumerate<::std::slice::IterMut<'a, T>>,
+    cols: usize
+}
+
+impl<'a, T: 'a> Iterator for IndicesMut<'a, T> {
+    type Item = (usize, usize, &'a mut T);
+
+    /// Produces the row index, column index, and a mutable reference to the
+    /// value of the next cell in the `Matrix`, or `None` if the `Iterator`
+    /// has reached its end.
+    fn next(&mut self) -> Option<(usize, usize, &'a mut T)> {
+        let cols = self.cols;
+        self.iter.next().map(|(index, val)| (index / cols, index % cols, val))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Naively multiplies two square matrices, for use in round-trip tests.
+    /// `Matrix`'s own `Mul` impl is scalar-only, not matrix product.
+    fn matmul(a: &Matrix<f64>, b: &Matrix<f64>) -> Matrix<f64> {
+        let n = a.rows();
+        let mut out = Matrix::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                let sum: f64 = (0..n).map(|k| a.get(i, k) * b.get(k, j)).sum();
+                out.set(i, j, sum);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn determinant_of_a_2x2() {
+        let m = matrix![1.0, 2.0; 3.0, 4.0];
+        assert_eq!(m.determinant(), -2.0);
+    }
+
+    #[test]
+    fn determinant_of_a_3x3() {
+        let m = matrix![6.0, 1.0, 1.0; 4.0, -2.0, 5.0; 2.0, 8.0, 7.0];
+        assert_eq!(m.determinant(), -306.0);
+    }
+
+    #[test]
+    fn singular_matrix_has_zero_determinant_and_no_inverse() {
+        let m = matrix![1.0, 2.0; 2.0, 4.0];
+        assert_eq!(m.determinant(), 0.0);
+        assert!(m.inverse().is_none());
+    }
+
+    #[test]
+    fn inverse_round_trips_to_the_identity() {
+        let m = matrix![6.0, 1.0, 1.0; 4.0, -2.0, 5.0; 2.0, 8.0, 7.0];
+        let inv = m.inverse().expect("matrix is non-singular");
+        let product = matmul(&m, &inv);
+        let identity = Matrix::<f64>::identity(3);
+
+        for (a, b) in product.iter().zip(identity.iter()) {
+            assert!((a - b).abs() < 1e-9, "{} !~= {}", a, b);
+        }
+    }
 }
\ No newline at end of file